@@ -1,11 +1,12 @@
 // TODO: Custom error type.
 // TODO: Figure out better conversions.
 
-use crate::{LetterCase, NotAlphabeticError, Result};
+use crate::{Case, LetterCase, NotAlphabeticError, Result};
+use std::cmp::Ordering;
 use std::fmt::Display;
 
 /// A type representing a letter of Latin-script alphabet.
-#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy, Hash)]
 pub struct AlphabeticLetter {
     index: u8,
     case: LetterCase,
@@ -79,7 +80,7 @@ impl AlphabeticLetter {
     # use alphabetic::{AlphabeticLetter, Result};
     # fn main() -> Result<()> {
     let mut letter = AlphabeticLetter::try_from('A')?;
-    assert_eq!(char::from(letter.shift(5)),'F');
+    assert_eq!(char::from(*letter.shift(5)),'F');
     # Ok(())
     # }
     ```
@@ -92,7 +93,7 @@ impl AlphabeticLetter {
     assert_eq!(new_string, "Must");
     ```
     */
-    pub fn shift(&mut self, amount: i32) -> &Self {
+    pub fn shift(&mut self, amount: i32) -> &mut Self {
         // Casting here should be safe, because of modulo and adding positive integer.
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         let offset: u8 =
@@ -100,6 +101,146 @@ impl AlphabeticLetter {
         self.index = (self.index + offset) % Self::ALPHABET_SIZE;
         self
     }
+
+    /**
+    Returns a copy of this [`AlphabeticLetter`] with its case changed to [`LetterCase::Uppercase`].
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let letter = AlphabeticLetter::try_from('a').unwrap();
+    assert_eq!(char::from(letter.to_uppercase()),'A');
+    ```
+    */
+    #[must_use]
+    pub fn to_uppercase(&self) -> AlphabeticLetter {
+        AlphabeticLetter {
+            index: self.index,
+            case: LetterCase::Uppercase,
+        }
+    }
+
+    /**
+    Returns a copy of this [`AlphabeticLetter`] with its case changed to [`LetterCase::Lowercase`].
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let letter = AlphabeticLetter::try_from('A').unwrap();
+    assert_eq!(char::from(letter.to_lowercase()),'a');
+    ```
+    */
+    #[must_use]
+    pub fn to_lowercase(&self) -> AlphabeticLetter {
+        AlphabeticLetter {
+            index: self.index,
+            case: LetterCase::Lowercase,
+        }
+    }
+
+    /**
+    Changes the case of this [`AlphabeticLetter`] to [`LetterCase::Uppercase`] in place.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let mut letter = AlphabeticLetter::try_from('a').unwrap();
+    letter.make_uppercase();
+    assert_eq!(char::from(letter),'A');
+    ```
+    */
+    pub fn make_uppercase(&mut self) -> &mut Self {
+        self.case = LetterCase::Uppercase;
+        self
+    }
+
+    /**
+    Changes the case of this [`AlphabeticLetter`] to [`LetterCase::Lowercase`] in place.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let mut letter = AlphabeticLetter::try_from('A').unwrap();
+    letter.make_lowercase();
+    assert_eq!(char::from(letter),'a');
+    ```
+    */
+    pub fn make_lowercase(&mut self) -> &mut Self {
+        self.case = LetterCase::Lowercase;
+        self
+    }
+
+    /**
+    Flips the case of this [`AlphabeticLetter`] in place, turning uppercase into lowercase and vice versa.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let mut letter = AlphabeticLetter::try_from('A').unwrap();
+    letter.toggle_case();
+    assert_eq!(char::from(letter),'a');
+    ```
+    */
+    pub fn toggle_case(&mut self) -> &mut Self {
+        self.case = match self.case {
+            LetterCase::Lowercase => LetterCase::Uppercase,
+            LetterCase::Uppercase => LetterCase::Lowercase,
+        };
+        self
+    }
+
+    /**
+    Compares two [`AlphabeticLetter`]s by position in alphabet only, ignoring case.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let lower = AlphabeticLetter::try_from('a').unwrap();
+    let upper = AlphabeticLetter::try_from('A').unwrap();
+    assert!(lower.eq_ignore_case(&upper));
+    ```
+    */
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &AlphabeticLetter) -> bool {
+        self.index == other.index
+    }
+
+    /**
+    Compares two [`AlphabeticLetter`]s, letting `case` pick whether letter case participates.
+
+    # Example
+    ```
+    # use alphabetic::{AlphabeticLetter, Case};
+    # use std::cmp::Ordering;
+    let lower = AlphabeticLetter::try_from('a').unwrap();
+    let upper = AlphabeticLetter::try_from('A').unwrap();
+    assert_eq!(lower.cmp_with(&upper, Case::Insensitive), Ordering::Equal);
+    assert_ne!(lower.cmp_with(&upper, Case::Sensitive), Ordering::Equal);
+    ```
+    */
+    #[must_use]
+    pub fn cmp_with(&self, other: &AlphabeticLetter, case: Case) -> Ordering {
+        match case {
+            Case::Sensitive => self.cmp(other),
+            Case::Insensitive => self.index.cmp(&other.index),
+        }
+    }
+
+    /**
+    Applies the classic ROT13 substitution, shifting the letter 13 places in the alphabet.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticLetter;
+    let mut letter = AlphabeticLetter::try_from('A').unwrap();
+    letter.rot13();
+    assert_eq!(char::from(letter),'N');
+    ```
+    */
+    pub fn rot13(&mut self) -> &mut Self {
+        self.shift(13);
+        self
+    }
 }
 
 impl TryFrom<u8> for AlphabeticLetter {
@@ -184,6 +325,7 @@ impl Display for AlphabeticLetter {
 mod tests {
 
     use super::{AlphabeticLetter, LetterCase};
+    use crate::Case;
 
     #[test]
     fn from_u8_lowercase() -> Result<(), Box<dyn std::error::Error>> {
@@ -264,4 +406,77 @@ mod tests {
         let vector = AlphabeticLetter::from_string("").unwrap();
         assert_eq!(vector.len(), 0);
     }
+    #[test]
+    fn to_uppercase_lowercase() -> Result<(), Box<dyn std::error::Error>> {
+        let lower = AlphabeticLetter::try_from('a')?;
+        let upper = AlphabeticLetter::try_from('A')?;
+        assert_eq!(lower.to_uppercase(), upper);
+        assert_eq!(upper.to_lowercase(), lower);
+        Ok(())
+    }
+    #[test]
+    fn make_uppercase_lowercase() -> Result<(), Box<dyn std::error::Error>> {
+        let mut letter = AlphabeticLetter::try_from('a')?;
+        letter.make_uppercase();
+        assert_eq!(char::from(letter), 'A');
+        letter.make_lowercase();
+        assert_eq!(char::from(letter), 'a');
+        Ok(())
+    }
+    #[test]
+    fn toggle_case() -> Result<(), Box<dyn std::error::Error>> {
+        let mut letter = AlphabeticLetter::try_from('g')?;
+        letter.toggle_case();
+        assert_eq!(char::from(letter), 'G');
+        letter.toggle_case();
+        assert_eq!(char::from(letter), 'g');
+        Ok(())
+    }
+    #[test]
+    fn ord_by_index_then_case() -> Result<(), Box<dyn std::error::Error>> {
+        let a_lower = AlphabeticLetter::try_from('a')?;
+        let a_upper = AlphabeticLetter::try_from('A')?;
+        let b_lower = AlphabeticLetter::try_from('b')?;
+        assert!(a_lower < a_upper);
+        assert!(a_upper < b_lower);
+        Ok(())
+    }
+    #[test]
+    fn eq_ignore_case() -> Result<(), Box<dyn std::error::Error>> {
+        let lower = AlphabeticLetter::try_from('q')?;
+        let upper = AlphabeticLetter::try_from('Q')?;
+        assert!(lower.eq_ignore_case(&upper));
+        assert_ne!(lower, upper);
+        Ok(())
+    }
+    #[test]
+    fn cmp_with_case() -> Result<(), Box<dyn std::error::Error>> {
+        let lower = AlphabeticLetter::try_from('q')?;
+        let upper = AlphabeticLetter::try_from('Q')?;
+        assert_eq!(
+            lower.cmp_with(&upper, Case::Insensitive),
+            std::cmp::Ordering::Equal
+        );
+        assert_ne!(
+            lower.cmp_with(&upper, Case::Sensitive),
+            std::cmp::Ordering::Equal
+        );
+        Ok(())
+    }
+    #[test]
+    fn rot13_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let mut letter = AlphabeticLetter::try_from('A')?;
+        letter.rot13();
+        assert_eq!(char::from(letter), 'N');
+        letter.rot13();
+        assert_eq!(char::from(letter), 'A');
+        Ok(())
+    }
+    #[test]
+    fn builder_methods_chain() -> Result<(), Box<dyn std::error::Error>> {
+        let mut letter = AlphabeticLetter::try_from('a')?;
+        letter.shift(1).make_uppercase().shift(1).rot13();
+        assert_eq!(char::from(letter), 'P');
+        Ok(())
+    }
 }
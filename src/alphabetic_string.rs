@@ -0,0 +1,183 @@
+use crate::{AlphabeticLetter, NotAlphabeticError, Result};
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// An owned, validated sequence of [`AlphabeticLetter`]s.
+#[derive(Debug, PartialEq, Eq, Default, Clone, Hash)]
+pub struct AlphabeticString(Vec<AlphabeticLetter>);
+
+impl AlphabeticString {
+    /**
+    Shifts every letter in the string by `amount` places forward or backward in alphabet.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticString;
+    let mut string: AlphabeticString = "Abc".parse().unwrap();
+    string.shift_all(1);
+    assert_eq!(string.to_string(), "Bcd");
+    ```
+    */
+    pub fn shift_all(&mut self, amount: i32) -> &mut Self {
+        for letter in &mut self.0 {
+            letter.shift(amount);
+        }
+        self
+    }
+
+    /**
+    Returns a copy of this [`AlphabeticString`] with every letter changed to uppercase.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticString;
+    let string: AlphabeticString = "Rust".parse().unwrap();
+    assert_eq!(string.to_uppercase().to_string(), "RUST");
+    ```
+    */
+    #[must_use]
+    pub fn to_uppercase(&self) -> AlphabeticString {
+        AlphabeticString(self.0.iter().map(AlphabeticLetter::to_uppercase).collect())
+    }
+
+    /**
+    Returns a copy of this [`AlphabeticString`] with every letter changed to lowercase.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticString;
+    let string: AlphabeticString = "Rust".parse().unwrap();
+    assert_eq!(string.to_lowercase().to_string(), "rust");
+    ```
+    */
+    #[must_use]
+    pub fn to_lowercase(&self) -> AlphabeticString {
+        AlphabeticString(self.0.iter().map(AlphabeticLetter::to_lowercase).collect())
+    }
+
+    /**
+    Compares this [`AlphabeticString`] to `other`, ignoring letter case.
+
+    # Example
+    ```
+    # use alphabetic::AlphabeticString;
+    let string: AlphabeticString = "Rust".parse().unwrap();
+    assert!(string.eq_ignore_case("RUST"));
+    assert!(!string.eq_ignore_case("Rust!"));
+    ```
+    */
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        let mut other_chars = other.chars();
+        self.0.len() == other.chars().count()
+            && self.0.iter().all(|letter| {
+                other_chars
+                    .next()
+                    .and_then(|c| AlphabeticLetter::try_from(c).ok())
+                    .is_some_and(|other_letter| letter.eq_ignore_case(&other_letter))
+            })
+    }
+}
+
+impl FromStr for AlphabeticString {
+    type Err = NotAlphabeticError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        AlphabeticLetter::from_string(input).map(AlphabeticString)
+    }
+}
+
+impl TryFrom<&str> for AlphabeticString {
+    type Error = NotAlphabeticError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl Deref for AlphabeticString {
+    type Target = [AlphabeticLetter];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for AlphabeticString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for letter in &self.0 {
+            write!(f, "{letter}")?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for AlphabeticString {
+    type Item = AlphabeticLetter;
+    type IntoIter = std::vec::IntoIter<AlphabeticLetter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::AlphabeticString;
+
+    #[test]
+    fn parse_and_display() -> Result<(), Box<dyn std::error::Error>> {
+        let string: AlphabeticString = "Hi".parse()?;
+        assert_eq!(string.to_string(), "Hi");
+        Ok(())
+    }
+    #[test]
+    fn try_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        let string = AlphabeticString::try_from("Rust")?;
+        assert_eq!(string.to_string(), "Rust");
+        Ok(())
+    }
+    #[test]
+    fn rejects_non_alphabetic() {
+        let result: Result<AlphabeticString, _> = "Hi!".parse();
+        assert!(result.is_err());
+    }
+    #[test]
+    fn deref_to_slice() -> Result<(), Box<dyn std::error::Error>> {
+        let string: AlphabeticString = "Rust".parse()?;
+        assert_eq!(string.len(), 4);
+        assert_eq!(char::from(string[0]), 'R');
+        Ok(())
+    }
+    #[test]
+    fn shift_all() -> Result<(), Box<dyn std::error::Error>> {
+        let mut string: AlphabeticString = "Abc".parse()?;
+        string.shift_all(1);
+        assert_eq!(string.to_string(), "Bcd");
+        Ok(())
+    }
+    #[test]
+    fn to_uppercase_lowercase() -> Result<(), Box<dyn std::error::Error>> {
+        let string: AlphabeticString = "Rust".parse()?;
+        assert_eq!(string.to_uppercase().to_string(), "RUST");
+        assert_eq!(string.to_lowercase().to_string(), "rust");
+        Ok(())
+    }
+    #[test]
+    fn eq_ignore_case() -> Result<(), Box<dyn std::error::Error>> {
+        let string: AlphabeticString = "Rust".parse()?;
+        assert!(string.eq_ignore_case("RUST"));
+        assert!(!string.eq_ignore_case("Rust!"));
+        assert!(!string.eq_ignore_case("Rus"));
+        Ok(())
+    }
+    #[test]
+    fn into_iterator() -> Result<(), Box<dyn std::error::Error>> {
+        let string: AlphabeticString = "Rust".parse()?;
+        let collected = string.into_iter().map(char::from).collect::<String>();
+        assert_eq!(collected, "Rust");
+        Ok(())
+    }
+}
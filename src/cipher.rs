@@ -0,0 +1,140 @@
+use crate::{AlphabeticLetter, NotAlphabeticError, Result};
+
+/**
+Encrypts a sequence of [`AlphabeticLetter`]s with a Vigenère cipher using `key`.
+
+Each letter of `text` is shifted forward by the alphabet position of the corresponding
+letter of `key`, cycling through `key` as needed. The case of every letter is preserved.
+
+# Example
+```
+# use alphabetic::{cipher, AlphabeticLetter};
+let text = AlphabeticLetter::from_string("ATTACKATDAWN").unwrap();
+let encrypted = cipher::encrypt_vigenere(&text, "LEMON").unwrap();
+let result = encrypted.into_iter().map(char::from).collect::<String>();
+assert_eq!(result, "LXFOPVEFRNHR");
+```
+
+# Errors
+Function will error if `key` is empty or contains any non-alphabetic characters.
+*/
+pub fn encrypt_vigenere(text: &[AlphabeticLetter], key: &str) -> Result<Vec<AlphabeticLetter>> {
+    vigenere(text, key, false)
+}
+
+/**
+Decrypts a sequence of [`AlphabeticLetter`]s previously encrypted with
+[`encrypt_vigenere`] using the same `key`.
+
+# Example
+```
+# use alphabetic::{cipher, AlphabeticLetter};
+let text = AlphabeticLetter::from_string("LXFOPVEFRNHR").unwrap();
+let decrypted = cipher::decrypt_vigenere(&text, "LEMON").unwrap();
+let result = decrypted.into_iter().map(char::from).collect::<String>();
+assert_eq!(result, "ATTACKATDAWN");
+```
+
+# Errors
+Function will error if `key` is empty or contains any non-alphabetic characters.
+*/
+pub fn decrypt_vigenere(text: &[AlphabeticLetter], key: &str) -> Result<Vec<AlphabeticLetter>> {
+    vigenere(text, key, true)
+}
+
+/**
+Applies the classic ROT13 substitution to a whole sequence of [`AlphabeticLetter`]s.
+
+ROT13 is its own inverse, so calling this function twice returns the original text.
+
+# Example
+```
+# use alphabetic::{cipher, AlphabeticLetter};
+let text = AlphabeticLetter::from_string("Attack").unwrap();
+let encrypted = cipher::rot13(&text);
+assert_eq!(encrypted.into_iter().map(char::from).collect::<String>(), "Nggnpx");
+```
+*/
+#[must_use]
+pub fn rot13(text: &[AlphabeticLetter]) -> Vec<AlphabeticLetter> {
+    text.iter()
+        .map(|letter| {
+            let mut letter = *letter;
+            letter.rot13();
+            letter
+        })
+        .collect()
+}
+
+fn vigenere(text: &[AlphabeticLetter], key: &str, decrypt: bool) -> Result<Vec<AlphabeticLetter>> {
+    if key.is_empty() {
+        return Err(NotAlphabeticError);
+    }
+    let key_indices = key
+        .chars()
+        .map(|letter| AlphabeticLetter::try_from(letter).map(|letter| letter.index()))
+        .collect::<Result<Vec<u8>>>()?;
+
+    Ok(text
+        .iter()
+        .enumerate()
+        .map(|(position, letter)| {
+            let mut letter = *letter;
+            let amount = i32::from(key_indices[position % key_indices.len()]);
+            letter.shift(if decrypt { -amount } else { amount });
+            letter
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{decrypt_vigenere, encrypt_vigenere, rot13};
+    use crate::{AlphabeticLetter, NotAlphabeticError};
+
+    fn letters(input: &str) -> Vec<AlphabeticLetter> {
+        AlphabeticLetter::from_string(input).unwrap()
+    }
+
+    fn to_string(letters: Vec<AlphabeticLetter>) -> String {
+        letters.into_iter().map(char::from).collect()
+    }
+
+    #[test]
+    fn encrypt_vigenere_known_vector() {
+        let encrypted = encrypt_vigenere(&letters("ATTACKATDAWN"), "LEMON").unwrap();
+        assert_eq!(to_string(encrypted), "LXFOPVEFRNHR");
+    }
+
+    #[test]
+    fn decrypt_vigenere_round_trip() {
+        let text = letters("AttackAtDawn");
+        let encrypted = encrypt_vigenere(&text, "lemon").unwrap();
+        let decrypted = decrypt_vigenere(&encrypted, "lemon").unwrap();
+        assert_eq!(
+            decrypted.into_iter().map(char::from).collect::<String>(),
+            to_string(text)
+        );
+    }
+
+    #[test]
+    fn empty_key_errors() {
+        let result = encrypt_vigenere(&letters("abc"), "");
+        assert!(matches!(result, Err(NotAlphabeticError)));
+    }
+
+    #[test]
+    fn non_alphabetic_key_errors() {
+        let result = encrypt_vigenere(&letters("abc"), "ke9");
+        assert!(matches!(result, Err(NotAlphabeticError)));
+    }
+
+    #[test]
+    fn rot13_round_trip() {
+        let text = letters("Attack");
+        let encrypted = rot13(&text);
+        assert_eq!(to_string(encrypted.clone()), "Nggnpx");
+        assert_eq!(to_string(rot13(&encrypted)), to_string(text));
+    }
+}
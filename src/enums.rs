@@ -1,5 +1,5 @@
 /// A type representing a letter case.
-#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy, Hash)]
 pub enum LetterCase {
     /// Lower case letter (e.g. 'a', 'b')
     #[default]
@@ -7,3 +7,13 @@ pub enum LetterCase {
     /// Upper case letter (e.g. 'A', 'B')
     Uppercase,
 }
+
+/// Selects whether comparisons should take letter case into account.
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Hash)]
+pub enum Case {
+    /// Case participates in the comparison.
+    #[default]
+    Sensitive,
+    /// Case is ignored; only the alphabet position is compared.
+    Insensitive,
+}
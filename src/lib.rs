@@ -28,8 +28,12 @@ assert_eq!(new_string, "Must");
 ```
 */
 mod alphabetic;
+mod alphabetic_string;
+/// Classical letter-substitution ciphers built on top of [`AlphabeticLetter::shift`].
+pub mod cipher;
 mod enums;
 mod error;
 pub use crate::alphabetic::*;
+pub use crate::alphabetic_string::*;
 pub use crate::enums::*;
 pub use crate::error::*;